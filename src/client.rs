@@ -1,26 +1,204 @@
 use crate::error::{Result, TurnkeyError};
 use crate::types::*;
+use crate::util::jitter;
+use rand_core::RngCore;
 use reqwest::Client;
+use serde::Serialize;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use turnkey_api_key_stamper::TurnkeyP256ApiKey;
 
 const TURNKEY_API_BASE_URL: &str = "https://api.turnkey.com";
 
+/// Retry/backoff policy applied to both activity submission (on transient
+/// network/5xx failures) and activity polling (while a status stays
+/// non-terminal).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Multiplier applied to the delay after each retry.
+    pub multiplier: f64,
+    /// Ceiling the backed-off delay is capped at.
+    pub max_delay: Duration,
+    /// Maximum number of submission attempts before giving up.
+    pub max_submit_attempts: u32,
+    /// HTTP timeout for each submission attempt. Polling and query requests
+    /// are sent without a per-request timeout.
+    pub per_attempt_timeout: Duration,
+    /// Overall wall-clock budget for polling an activity to completion.
+    pub poll_timeout: Duration,
+}
+
 #[cfg(not(test))]
-const ACTIVITY_POLL_INTERVAL_MS: u64 = 1000; // 1 second
-#[cfg(not(test))]
-const ACTIVITY_TIMEOUT_MS: u64 = 30000; // 30 seconds
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(250),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(5),
+            max_submit_attempts: 3,
+            per_attempt_timeout: Duration::from_secs(10),
+            poll_timeout: Duration::from_secs(30),
+        }
+    }
+}
 
 #[cfg(test)]
-const ACTIVITY_POLL_INTERVAL_MS: u64 = 10; // 10ms for tests
-#[cfg(test)]
-const ACTIVITY_TIMEOUT_MS: u64 = 1000; // 1 second for tests
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(10),
+            multiplier: 2.0,
+            max_delay: Duration::from_millis(50),
+            max_submit_attempts: 3,
+            per_attempt_timeout: Duration::from_secs(10),
+            poll_timeout: Duration::from_millis(1000),
+        }
+    }
+}
+
+/// A Turnkey activity request: where it submits to, and how to pull its
+/// typed result back out of the completed activity.
+///
+/// Implementing this for a request type is what lets `submit_activity`/
+/// `execute` route it correctly instead of every activity hard-coding the
+/// same `sign_transaction` endpoint.
+pub trait Activity: Serialize {
+    /// The decoded shape of this activity's completed result.
+    type Result: serde::de::DeserializeOwned;
+
+    /// The `POST /public/v1/submit/<path>` segment this activity submits to.
+    fn submit_path(&self) -> &'static str;
+
+    /// The key this activity's result is nested under in
+    /// `activity.result.data`.
+    fn result_key(&self) -> &'static str;
+}
+
+impl Activity for SignTransactionRequest {
+    type Result = SignTransactionResult;
+
+    fn submit_path(&self) -> &'static str {
+        "sign_transaction"
+    }
+
+    fn result_key(&self) -> &'static str {
+        "signTransactionResult"
+    }
+}
+
+/// Which signing scheme a raw-payload sign request should use. Turnkey signs
+/// with more than just Ethereum's secp256k1 keys (e.g. ed25519 for Solana),
+/// and each expects a different `encoding`/`hashFunction` combination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningScheme {
+    EcdsaSecp256k1,
+    Ed25519,
+}
+
+impl SigningScheme {
+    fn encoding(self) -> &'static str {
+        match self {
+            Self::EcdsaSecp256k1 => "PAYLOAD_ENCODING_HEXADECIMAL",
+            Self::Ed25519 => "PAYLOAD_ENCODING_HEXADECIMAL",
+        }
+    }
+
+    fn hash_function(self) -> &'static str {
+        match self {
+            Self::EcdsaSecp256k1 => "HASH_FUNCTION_NO_OP",
+            Self::Ed25519 => "HASH_FUNCTION_NOT_APPLICABLE",
+        }
+    }
+}
+
+/// A raw-payload signature in the shape its signing scheme produces:
+/// secp256k1 carries a recovery `v` alongside r/s, while ed25519 has no
+/// recovery id and is reported as the canonical 64-byte `r ‖ s` signature.
+#[derive(Debug, Clone)]
+pub enum RawSignature {
+    EcdsaSecp256k1 { r: String, s: String, v: String },
+    Ed25519 { signature: [u8; 64] },
+}
+
+impl Activity for SignRawPayloadRequest {
+    type Result = SignRawPayloadResult;
+
+    fn submit_path(&self) -> &'static str {
+        "sign_raw_payload"
+    }
+
+    fn result_key(&self) -> &'static str {
+        "signRawPayloadResult"
+    }
+}
+
+impl Activity for SignTransactionV2Request {
+    type Result = SignTransactionResult;
+
+    fn submit_path(&self) -> &'static str {
+        "sign_transaction"
+    }
+
+    fn result_key(&self) -> &'static str {
+        "signTransactionResult"
+    }
+}
+
+impl Activity for CreateWalletAccountsRequest {
+    type Result = CreateWalletAccountsResult;
+
+    fn submit_path(&self) -> &'static str {
+        "create_wallet_accounts"
+    }
+
+    fn result_key(&self) -> &'static str {
+        "createWalletAccountsResult"
+    }
+}
+
+impl Activity for ExportWalletRequest {
+    type Result = ExportResult;
+
+    fn submit_path(&self) -> &'static str {
+        "export_wallet"
+    }
+
+    fn result_key(&self) -> &'static str {
+        "exportWalletResult"
+    }
+}
+
+impl Activity for ExportPrivateKeyRequest {
+    type Result = ExportResult;
+
+    fn submit_path(&self) -> &'static str {
+        "export_private_key"
+    }
+
+    fn result_key(&self) -> &'static str {
+        "exportPrivateKeyResult"
+    }
+}
+
+impl Activity for ImportWalletRequest {
+    type Result = ImportWalletResult;
+
+    fn submit_path(&self) -> &'static str {
+        "import_wallet"
+    }
+
+    fn result_key(&self) -> &'static str {
+        "importWalletResult"
+    }
+}
 
 pub struct TurnkeyClient {
     client: Client,
     api_key: TurnkeyP256ApiKey,
     organization_id: String,
     base_url: String,
+    retry_policy: RetryPolicy,
 }
 
 impl TurnkeyClient {
@@ -32,6 +210,7 @@ impl TurnkeyClient {
             api_key,
             organization_id,
             base_url: TURNKEY_API_BASE_URL.to_string(),
+            retry_policy: RetryPolicy::default(),
         })
     }
 
@@ -40,6 +219,13 @@ impl TurnkeyClient {
         self
     }
 
+    /// Override the retry/backoff policy used for activity submission and
+    /// polling, e.g. to tune for a high-latency environment.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Sign a transaction using either a private key ID or wallet ID
     pub async fn sign_transaction(
         &self,
@@ -64,28 +250,103 @@ impl TurnkeyClient {
             timestamp_ms: self.current_timestamp_ms(),
         };
 
-        let activity = self.submit_activity(&request).await?;
-        let completed_activity = self.poll_activity(&activity.id).await?;
+        self.execute(&request).await
+    }
 
-        if let Some(result) = completed_activity.result {
-            let sign_result: SignTransactionResult = serde_json::from_value(
-                result
-                    .data
-                    .get("signTransactionResult")
-                    .ok_or_else(|| TurnkeyError::Api {
-                        code: "MISSING_RESULT".to_string(),
-                        message: "Missing signTransactionResult in response".to_string(),
-                    })?
-                    .clone(),
-            )?;
-            Ok(sign_result)
-        } else {
-            Err(TurnkeyError::ActivityFailed(
-                "No result in completed activity".to_string(),
-            ))
+    /// Sign a transaction via Turnkey's `ACTIVITY_TYPE_SIGN_TRANSACTION_V2`,
+    /// addressing the signer polymorphically (a private key id, wallet
+    /// account address, or wallet id) rather than requiring a pre-resolved
+    /// private key id as [`TurnkeyClient::sign_transaction`] does.
+    pub async fn sign_transaction_v2(
+        &self,
+        sign_with: &str,
+        unsigned_transaction: &str,
+    ) -> Result<SignTransactionResult> {
+        let request = SignTransactionV2Request {
+            activity_type: "ACTIVITY_TYPE_SIGN_TRANSACTION_V2".to_string(),
+            organization_id: self.organization_id.clone(),
+            parameters: SignTransactionV2Parameters {
+                sign_with: sign_with.to_string(),
+                unsigned_transaction: unsigned_transaction.to_string(),
+                transaction_type: "TRANSACTION_TYPE_ETHEREUM".to_string(),
+            },
+            timestamp_ms: self.current_timestamp_ms(),
+        };
+
+        self.execute(&request).await
+    }
+
+    /// Sign a raw payload using the Turnkey `encoding`/`hashFunction`
+    /// combination a given [`SigningScheme`] expects, returning a
+    /// scheme-aware result instead of forcing every curve through the
+    /// secp256k1 r/s/v shape.
+    pub async fn sign_raw_payload_with_scheme(
+        &self,
+        private_key_id: &str,
+        payload: &str,
+        scheme: SigningScheme,
+    ) -> Result<RawSignature> {
+        let result = self
+            .sign_raw_payload(
+                private_key_id,
+                payload,
+                scheme.encoding(),
+                scheme.hash_function(),
+            )
+            .await?;
+
+        match scheme {
+            SigningScheme::EcdsaSecp256k1 => Ok(RawSignature::EcdsaSecp256k1 {
+                r: result.r,
+                s: result.s,
+                v: result.v,
+            }),
+            SigningScheme::Ed25519 => {
+                let r = hex::decode(&result.r)?;
+                let s = hex::decode(&result.s)?;
+                if r.len() != 32 || s.len() != 32 {
+                    return Err(TurnkeyError::SignatureParse(format!(
+                        "expected 32-byte r/s for an ed25519 signature, got {}/{} bytes",
+                        r.len(),
+                        s.len()
+                    )));
+                }
+                let mut signature = [0u8; 64];
+                signature[..32].copy_from_slice(&r);
+                signature[32..].copy_from_slice(&s);
+                Ok(RawSignature::Ed25519 { signature })
+            }
         }
     }
 
+    /// Signs an arbitrary payload with an ed25519 key, returning the
+    /// canonical 64-byte `r ‖ s` signature rather than Turnkey's raw r/s/v
+    /// fields, so non-EVM callers don't need to know Turnkey's encoding
+    /// constants.
+    pub async fn sign_ed25519(&self, private_key_id: &str, payload: &[u8]) -> Result<[u8; 64]> {
+        let payload_hex = hex::encode(payload);
+        match self
+            .sign_raw_payload_with_scheme(private_key_id, &payload_hex, SigningScheme::Ed25519)
+            .await?
+        {
+            RawSignature::Ed25519 { signature } => Ok(signature),
+            RawSignature::EcdsaSecp256k1 { .. } => {
+                unreachable!("sign_ed25519 always requests SigningScheme::Ed25519")
+            }
+        }
+    }
+
+    /// Signs a Solana message with an ed25519 key. Thin naming sugar over
+    /// [`TurnkeyClient::sign_ed25519`] for callers coming from Solana wallet
+    /// tooling that think in terms of "messages" rather than raw payloads.
+    pub async fn sign_solana_message(
+        &self,
+        private_key_id: &str,
+        message: &[u8],
+    ) -> Result<[u8; 64]> {
+        self.sign_ed25519(private_key_id, message).await
+    }
+
     /// Sign raw payload for message signing
     pub async fn sign_raw_payload(
         &self,
@@ -106,73 +367,298 @@ impl TurnkeyClient {
             timestamp_ms: self.current_timestamp_ms(),
         };
 
-        let activity = self.submit_activity(&request).await?;
+        self.execute(&request).await
+    }
+
+    /// Lists the wallets owned by the organization, to enumerate their
+    /// accounts via [`TurnkeyClient::list_wallet_accounts`].
+    pub async fn list_wallets(&self) -> Result<Vec<Wallet>> {
+        let response = self
+            .client
+            .get(format!("{}/public/v1/query/list_wallets", self.base_url))
+            .query(&[("organizationId", self.organization_id.as_str())])
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            let result: ListWalletsResult = response.json().await?;
+            Ok(result.wallets)
+        } else {
+            Err(api_error(response).await)
+        }
+    }
+
+    /// Lists the accounts (addresses/public keys) held by a wallet.
+    pub async fn list_wallet_accounts(&self, wallet_id: &str) -> Result<Vec<WalletAccount>> {
+        let response = self
+            .client
+            .get(format!(
+                "{}/public/v1/query/list_wallet_accounts",
+                self.base_url
+            ))
+            .query(&[
+                ("organizationId", self.organization_id.as_str()),
+                ("walletId", wallet_id),
+            ])
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            let result: ListWalletAccountsResult = response.json().await?;
+            Ok(result.accounts)
+        } else {
+            Err(api_error(response).await)
+        }
+    }
+
+    /// Resolves the account at `derivation_path` on an existing wallet,
+    /// returning its full id/address/public key, so callers don't have to
+    /// pre-know a `private_key_id` or address for every account they use.
+    ///
+    /// Looks the account up via [`TurnkeyClient::list_wallet_accounts`]
+    /// first and only submits a create activity if none exists yet at that
+    /// path, so calling this repeatedly for the same path (e.g. every
+    /// [`crate::signer::TurnkeySigner::from_wallet`] construction) resolves
+    /// the existing account instead of minting a new one each time.
+    pub async fn derive_account(
+        &self,
+        wallet_id: &str,
+        derivation_path: &str,
+    ) -> Result<WalletAccount> {
+        if let Some(account) = self.find_wallet_account(wallet_id, derivation_path).await? {
+            return Ok(account);
+        }
+
+        let request = CreateWalletAccountsRequest {
+            activity_type: "ACTIVITY_TYPE_CREATE_WALLET_ACCOUNTS".to_string(),
+            organization_id: self.organization_id.clone(),
+            parameters: CreateWalletAccountsParameters {
+                wallet_id: wallet_id.to_string(),
+                accounts: vec![WalletAccountParams {
+                    curve: "CURVE_SECP256K1".to_string(),
+                    path_format: "PATH_FORMAT_BIP32".to_string(),
+                    path: derivation_path.to_string(),
+                    address_format: "ADDRESS_FORMAT_ETHEREUM".to_string(),
+                }],
+            },
+            timestamp_ms: self.current_timestamp_ms(),
+        };
+        self.execute(&request).await?;
+
+        self.find_wallet_account(wallet_id, derivation_path)
+            .await?
+            .ok_or_else(|| TurnkeyError::Api {
+                code: "MISSING_RESULT".to_string(),
+                message: "Turnkey did not report the newly created wallet account".to_string(),
+            })
+    }
+
+    /// Looks up an existing wallet account by derivation path.
+    async fn find_wallet_account(
+        &self,
+        wallet_id: &str,
+        derivation_path: &str,
+    ) -> Result<Option<WalletAccount>> {
+        Ok(self
+            .list_wallet_accounts(wallet_id)
+            .await?
+            .into_iter()
+            .find(|account| account.path == derivation_path))
+    }
+
+    /// Exports an entire wallet's seed, encrypted to `recipient`'s public
+    /// key, as a sealed bundle the caller decrypts with that same keypair.
+    pub async fn export_wallet(
+        &self,
+        wallet_id: &str,
+        recipient: &crate::export::RecipientKeyPair,
+    ) -> Result<crate::export::SealedBundle> {
+        let request = ExportWalletRequest {
+            activity_type: "ACTIVITY_TYPE_EXPORT_WALLET".to_string(),
+            organization_id: self.organization_id.clone(),
+            parameters: ExportWalletParameters {
+                wallet_id: wallet_id.to_string(),
+                target_public_key: recipient.public_key_hex(),
+            },
+            timestamp_ms: self.current_timestamp_ms(),
+        };
+
+        let result = self.execute(&request).await?;
+        Ok(crate::export::SealedBundle {
+            bundle: result.export_bundle,
+        })
+    }
+
+    /// Exports a single private key, encrypted to `recipient`'s public key,
+    /// as a sealed bundle the caller decrypts with that same keypair.
+    pub async fn export_private_key(
+        &self,
+        private_key_id: &str,
+        recipient: &crate::export::RecipientKeyPair,
+    ) -> Result<crate::export::SealedBundle> {
+        let request = ExportPrivateKeyRequest {
+            activity_type: "ACTIVITY_TYPE_EXPORT_PRIVATE_KEY".to_string(),
+            organization_id: self.organization_id.clone(),
+            parameters: ExportPrivateKeyParameters {
+                private_key_id: private_key_id.to_string(),
+                target_public_key: recipient.public_key_hex(),
+            },
+            timestamp_ms: self.current_timestamp_ms(),
+        };
+
+        let result = self.execute(&request).await?;
+        Ok(crate::export::SealedBundle {
+            bundle: result.export_bundle,
+        })
+    }
+
+    /// Imports a wallet from a bundle the caller has already encrypted to
+    /// this organization, returning the new wallet's id.
+    pub async fn import_wallet(
+        &self,
+        wallet_name: &str,
+        encrypted_bundle: &crate::export::SealedBundle,
+    ) -> Result<String> {
+        let request = ImportWalletRequest {
+            activity_type: "ACTIVITY_TYPE_IMPORT_WALLET".to_string(),
+            organization_id: self.organization_id.clone(),
+            parameters: ImportWalletParameters {
+                wallet_name: wallet_name.to_string(),
+                encrypted_bundle: encrypted_bundle.bundle.clone(),
+            },
+            timestamp_ms: self.current_timestamp_ms(),
+        };
+
+        let result = self.execute(&request).await?;
+        Ok(result.wallet_id)
+    }
+
+    /// Submits an activity and polls it to completion, decoding its typed
+    /// result. This is the single path every activity-specific method above
+    /// goes through, so adding a new activity only means implementing
+    /// [`Activity`] for its request type rather than hand-rolling another
+    /// submit/poll/extract method.
+    async fn execute<A: Activity>(&self, request: &A) -> Result<A::Result> {
+        let activity = self.submit_activity(request).await?;
         let completed_activity = self.poll_activity(&activity.id).await?;
 
         if let Some(result) = completed_activity.result {
-            let sign_result: SignRawPayloadResult = serde_json::from_value(
+            let decoded: A::Result = serde_json::from_value(
                 result
                     .data
-                    .get("signRawPayloadResult")
+                    .get(request.result_key())
                     .ok_or_else(|| TurnkeyError::Api {
                         code: "MISSING_RESULT".to_string(),
-                        message: "Missing signRawPayloadResult in response".to_string(),
+                        message: format!("Missing {} in response", request.result_key()),
                     })?
                     .clone(),
             )?;
-            Ok(sign_result)
+            Ok(decoded)
         } else {
-            Err(TurnkeyError::ActivityFailed(
-                "No result in completed activity".to_string(),
-            ))
+            Err(TurnkeyError::ActivityFailed {
+                activity_id: completed_activity.id,
+                message: "No result in completed activity".to_string(),
+            })
         }
     }
 
     // Helper methods for activity management
-    async fn submit_activity<T: serde::Serialize>(&self, request: &T) -> Result<Activity> {
+    async fn submit_activity<A: Activity>(&self, request: &A) -> Result<crate::types::Activity> {
         let body = serde_json::to_vec(request)?;
-        
+
         // Use the TurnkeyP256ApiKey to create proper headers
         let body_string = String::from_utf8(body.clone())?;
-        let stamped_headers = self.api_key.stamp(&body_string, &self.current_timestamp_ms())?;
+        let stamped_headers = self
+            .api_key
+            .stamp(&body_string, &self.current_timestamp_ms())
+            .map_err(|e| TurnkeyError::Stamp(e.to_string()))?;
 
-        let response = self
-            .client
-            .post(format!("{}/public/v1/submit/sign_transaction", self.base_url))
-            .header("Content-Type", "application/json")
-            .header("X-Stamp-WebAuthn", &stamped_headers)
-            .body(body)
-            .send()
-            .await?;
+        // Generated once per submission (not derived from the body, which
+        // embeds timestamp_ms and would otherwise need to be excluded from
+        // any content hash) so every retry of this activity carries the same
+        // key instead of the server seeing each attempt as a new activity.
+        let idempotency_key = idempotency_key();
 
-        if response.status().is_success() {
-            let activity_response: ActivityResponse = response.json().await?;
-            Ok(activity_response.activity)
-        } else {
-            let error_text = response.text().await?;
-            Err(TurnkeyError::Api {
-                code: "HTTP_ERROR".to_string(),
-                message: format!("HTTP error: {}", error_text),
-            })
+        let mut delay = self.retry_policy.base_delay;
+        let mut last_err = None;
+
+        for attempt in 0..self.retry_policy.max_submit_attempts {
+            let result = self
+                .client
+                .post(format!(
+                    "{}/public/v1/submit/{}",
+                    self.base_url,
+                    request.submit_path()
+                ))
+                .header("Content-Type", "application/json")
+                .header("X-Stamp-WebAuthn", &stamped_headers)
+                .header("X-Idempotency-Key", &idempotency_key)
+                .timeout(self.retry_policy.per_attempt_timeout)
+                .body(body.clone())
+                .send()
+                .await;
+
+            let retry_after = match result {
+                Ok(response) if response.status().is_success() => {
+                    let activity_response: ActivityResponse = response.json().await?;
+                    return Ok(activity_response.activity);
+                }
+                Ok(response) => {
+                    // Retry on a server error even if Turnkey's body didn't
+                    // decode to a known retryable code, and on a decoded
+                    // code (e.g. a 429's RATE_LIMITED) even when the status
+                    // itself isn't a 5xx.
+                    let is_server_error = response.status().is_server_error();
+                    let error = api_error(response).await;
+                    let retryable = is_server_error || error.is_retryable();
+                    last_err = Some(error);
+                    retryable
+                }
+                Err(e) => {
+                    let retryable = e.is_timeout() || e.is_connect();
+                    last_err = Some(TurnkeyError::from(e));
+                    retryable
+                }
+            };
+
+            let is_last_attempt = attempt + 1 == self.retry_policy.max_submit_attempts;
+            if !retry_after || is_last_attempt {
+                break;
+            }
+
+            tokio::time::sleep(jitter(delay)).await;
+            delay = delay.mul_f64(self.retry_policy.multiplier).min(self.retry_policy.max_delay);
         }
+
+        Err(last_err.unwrap_or_else(|| TurnkeyError::Api {
+            code: "SUBMIT_EXHAUSTED".to_string(),
+            message: "exhausted submit retries without a response".to_string(),
+        }))
     }
 
-    async fn poll_activity(&self, activity_id: &str) -> Result<Activity> {
+    /// Polls an already-submitted activity by id until it reaches a terminal
+    /// status, backing off per [`TurnkeyClient::with_retry_policy`].
+    ///
+    /// `pub(crate)` so [`TurnkeyClient::execute`] isn't the only path that can
+    /// wait out a pending activity (e.g. one awaiting multi-approver
+    /// consensus) by id instead of resubmitting it.
+    pub(crate) async fn poll_activity(&self, activity_id: &str) -> Result<crate::types::Activity> {
         let start_time = SystemTime::now();
-        let timeout = Duration::from_millis(ACTIVITY_TIMEOUT_MS);
+        let mut delay = self.retry_policy.base_delay;
 
         loop {
-            if start_time.elapsed().unwrap_or(Duration::ZERO) > timeout {
-                return Err(TurnkeyError::Api {
-                    code: "TIMEOUT".to_string(),
-                    message: format!("Activity {} timed out", activity_id),
+            if start_time.elapsed().unwrap_or(Duration::ZERO) > self.retry_policy.poll_timeout {
+                return Err(TurnkeyError::ActivityTimeout {
+                    activity_id: activity_id.to_string(),
                 });
             }
 
             let response = self
                 .client
-                .get(format!("{}/public/v1/query/get_activity?activityId={}&organizationId={}", 
-                    self.base_url, activity_id, self.organization_id))
+                .get(format!(
+                    "{}/public/v1/query/get_activity?activityId={}&organizationId={}",
+                    self.base_url, activity_id, self.organization_id
+                ))
                 .send()
                 .await?;
 
@@ -180,16 +666,25 @@ impl TurnkeyClient {
                 let activity_response: ActivityResponse = response.json().await?;
                 let activity = activity_response.activity;
 
-                if activity.status == "ACTIVITY_STATUS_COMPLETED" {
-                    return Ok(activity);
-                } else if activity.status == "ACTIVITY_STATUS_FAILED" {
-                    return Err(TurnkeyError::ActivityFailed(
-                        activity.result.map_or("Unknown error".to_string(), |r| r.error),
-                    ));
+                match activity.status.as_str() {
+                    "ACTIVITY_STATUS_COMPLETED" => return Ok(activity),
+                    "ACTIVITY_STATUS_REJECTED" => {
+                        return Err(TurnkeyError::ActivityRejected {
+                            activity_id: activity_id.to_string(),
+                        })
+                    }
+                    "ACTIVITY_STATUS_FAILED" => {
+                        return Err(TurnkeyError::ActivityFailed {
+                            activity_id: activity_id.to_string(),
+                            message: activity.result.map_or("Unknown error".to_string(), |r| r.error),
+                        })
+                    }
+                    _ => {}
                 }
             }
 
-            tokio::time::sleep(Duration::from_millis(ACTIVITY_POLL_INTERVAL_MS)).await;
+            tokio::time::sleep(jitter(delay)).await;
+            delay = delay.mul_f64(self.retry_policy.multiplier).min(self.retry_policy.max_delay);
         }
     }
 
@@ -201,3 +696,36 @@ impl TurnkeyClient {
             .to_string()
     }
 }
+
+/// Builds a `TurnkeyError::Api` from a non-success response, decoding
+/// Turnkey's `{code, message}` error body so the real API error code (e.g.
+/// `RATE_LIMITED`) survives for [`TurnkeyError::is_retryable`] rather than
+/// every HTTP failure collapsing into an opaque, always-non-retryable
+/// `HTTP_ERROR`. Falls back to a synthetic code if the body doesn't parse.
+async fn api_error(response: reqwest::Response) -> TurnkeyError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    match serde_json::from_str::<ErrorResponse>(&body) {
+        Ok(error) => TurnkeyError::Api {
+            code: error.code,
+            message: error.message,
+        },
+        Err(_) => TurnkeyError::Api {
+            code: "HTTP_ERROR".to_string(),
+            message: format!("HTTP error {status}: {body}"),
+        },
+    }
+}
+
+/// Generates a fresh 128-bit idempotency key. A content hash of the stamped
+/// body would double back on itself (the body embeds `timestamp_ms`, so
+/// every attempt would need to agree on which bytes to hash), and a 64-bit
+/// hash is a thin collision space for a token meant to dedupe activities
+/// server-side; random bytes avoid both problems at the cost of needing to
+/// be generated once per request and reused across its retries, which
+/// `submit_activity` already does.
+fn idempotency_key() -> String {
+    let mut bytes = [0u8; 16];
+    rand_core::OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
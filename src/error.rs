@@ -2,11 +2,44 @@ use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, TurnkeyError>;
 
+/// Turnkey API error codes, classified by whether retrying is likely to help.
+///
+/// Transient conditions (rate limits, 5xxs) are worth retrying; permanent
+/// ones (a bad stamp, a rejected activity) aren't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TurnkeyApiErrorCode {
+    AuthenticationFailed,
+    RateLimited,
+    InvalidParameter,
+    ActivityRejected,
+    ResourceNotFound,
+    Internal,
+    Unknown(String),
+}
+
+impl TurnkeyApiErrorCode {
+    fn from_code(code: &str) -> Self {
+        match code {
+            "AUTHENTICATION_ERROR" | "INVALID_STAMP" | "UNAUTHENTICATED" => {
+                Self::AuthenticationFailed
+            }
+            "RATE_LIMITED" | "TOO_MANY_REQUESTS" => Self::RateLimited,
+            "INVALID_PARAMETER" | "INVALID_REQUEST" => Self::InvalidParameter,
+            "ACTIVITY_REJECTED" => Self::ActivityRejected,
+            "RESOURCE_NOT_FOUND" | "NOT_FOUND" => Self::ResourceNotFound,
+            "INTERNAL_ERROR" | "INTERNAL_SERVER_ERROR" => Self::Internal,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+
+    /// Whether a caller should expect retrying this error to succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::RateLimited | Self::Internal)
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum TurnkeyError {
-    #[error("Turnkey SDK error: {0}")]
-    Sdk(#[from] turnkey_client::TurnkeyClientError),
-
     #[error("Configuration error: {0}")]
     Configuration(String),
 
@@ -33,4 +66,54 @@ pub enum TurnkeyError {
 
     #[error("String from UTF8 error: {0}")]
     Utf8(#[from] std::string::FromUtf8Error),
+
+    /// The activity is still awaiting consensus/approval and has not reached
+    /// a terminal status yet.
+    #[error("Activity {activity_id} is still pending")]
+    ActivityPending { activity_id: String },
+
+    /// The activity was explicitly rejected by an approver.
+    #[error("Activity {activity_id} was rejected")]
+    ActivityRejected { activity_id: String },
+
+    /// Polling for a terminal status exceeded the configured timeout.
+    #[error("Timed out waiting for activity {activity_id} to complete")]
+    ActivityTimeout { activity_id: String },
+
+    /// An activity reached a terminal failed status.
+    #[error("Activity {activity_id} failed: {message}")]
+    ActivityFailed { activity_id: String, message: String },
+
+    #[error("HTTP request error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Failed to stamp request: {0}")]
+    Stamp(String),
+}
+
+impl TurnkeyError {
+    /// Whether a caller should expect retrying the operation that produced
+    /// this error to succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Api { code, .. } => TurnkeyApiErrorCode::from_code(code).is_retryable(),
+            Self::ActivityPending { .. } => true,
+            Self::ActivityRejected { .. } | Self::ActivityTimeout { .. } => false,
+            Self::ActivityFailed { .. } => false,
+            Self::Http(e) => e.is_timeout() || e.is_connect() || e.status().is_some_and(|s| s.is_server_error()),
+            Self::Configuration(_)
+            | Self::InvalidAddress(_)
+            | Self::HexDecode(_)
+            | Self::SignatureParse(_)
+            | Self::MissingParameter(_)
+            | Self::AlloySignature(_)
+            | Self::ParseInt(_)
+            | Self::Utf8(_)
+            | Self::Json(_)
+            | Self::Stamp(_) => false,
+        }
+    }
 }
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+/// A P-256 keypair generated as the target recipient for a Turnkey encrypted
+/// export bundle (or the source of one being imported). Turnkey encrypts
+/// exported key material to this key's public half so it's never exposed to
+/// Turnkey's own infrastructure in the clear.
+pub struct RecipientKeyPair {
+    secret_key: p256::SecretKey,
+}
+
+impl RecipientKeyPair {
+    /// Generates a fresh recipient keypair.
+    pub fn generate() -> Self {
+        Self {
+            secret_key: p256::SecretKey::random(&mut rand_core::OsRng),
+        }
+    }
+
+    /// Reconstructs a recipient keypair from a secret saved via
+    /// [`RecipientKeyPair::secret_key_bytes`], so a caller can persist it
+    /// between generating the recipient key (to pass to
+    /// [`crate::client::TurnkeyClient::export_wallet`]) and receiving the
+    /// [`SealedBundle`] it was the target of, rather than holding both in
+    /// the same process lifetime.
+    pub fn from_secret_key(secret_key: p256::SecretKey) -> Self {
+        Self { secret_key }
+    }
+
+    /// The raw secret scalar, zeroized on drop, for feeding into whichever
+    /// HPKE implementation unseals a [`SealedBundle`] this key was the
+    /// target of — see [`SealedBundle`] for why that step isn't done here.
+    pub fn secret_key_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.secret_key.to_bytes().to_vec())
+    }
+
+    /// The uncompressed (`0x04…`) public key, hex-encoded, to hand to
+    /// Turnkey as the `targetPublicKey` for its HPKE-sealed export/import
+    /// bundles — the form Turnkey's API expects, not the compressed SEC1
+    /// encoding.
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.secret_key.public_key().to_encoded_point(false).as_bytes())
+    }
+}
+
+/// An encrypted export bundle as returned by Turnkey, sealed via HPKE
+/// (`DHKEM(P-256, HKDF-SHA256)`) to the public key of a [`RecipientKeyPair`].
+///
+/// Unsealing it is the caller's responsibility, using
+/// [`RecipientKeyPair::secret_key_bytes`] and whichever HPKE implementation
+/// their deployment already trusts; this crate only moves the opaque bundle
+/// in and out of Turnkey's API; it does not depend on an HPKE crate itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealedBundle {
+    pub bundle: String,
+}
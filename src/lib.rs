@@ -3,11 +3,19 @@
 //! This crate provides a signer implementation that uses Turnkey's secure infrastructure
 //! for managing private keys and signing transactions.
 
+pub mod client;
 pub mod error;
+pub mod export;
+pub mod nonce;
+pub mod signature;
 pub mod signer;
+pub mod types;
+mod util;
+pub mod wallet;
 
-pub use error::{Result, TurnkeyError};
+pub use error::{Result, TurnkeyApiErrorCode, TurnkeyError};
 pub use signer::TurnkeySigner;
+pub use wallet::TurnkeyWallet;
 
 // Re-export key types from the official Turnkey SDK
 pub use turnkey_client::TurnkeyP256ApiKey;
@@ -0,0 +1,53 @@
+use crate::error::{Result, TurnkeyError};
+use alloy_primitives::Address;
+use alloy_provider::Provider;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Tracks the next nonce for an address locally instead of re-querying
+/// `eth_getTransactionCount` before every transaction.
+///
+/// Turnkey's `SIGN_TRANSACTION_V2` activity completes asynchronously (and
+/// can sit pending behind multi-approver consensus), so two `sign_transaction`
+/// calls issued back-to-back can easily both observe the same on-chain
+/// transaction count and race for the same nonce. Routing every caller
+/// through a shared `NonceManager` instead hands out monotonically
+/// increasing values from the moment it's constructed.
+pub struct NonceManager {
+    next: AtomicU64,
+}
+
+impl NonceManager {
+    /// Initializes the counter from the account's current on-chain
+    /// transaction count.
+    pub async fn new<P: Provider>(provider: &P, address: Address) -> Result<Self> {
+        let count = provider
+            .get_transaction_count(address)
+            .await
+            .map_err(|e| TurnkeyError::Configuration(e.to_string()))?;
+
+        Ok(Self {
+            next: AtomicU64::new(count),
+        })
+    }
+
+    /// Hands out the next nonce, incrementing the local counter.
+    pub fn next_nonce(&self) -> u64 {
+        self.next.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Re-fetches the nonce from chain, discarding the local counter.
+    ///
+    /// Call this after a detected gap (e.g. a submission failure, a dropped
+    /// transaction, or a nonce-too-low response) so subsequent calls to
+    /// [`NonceManager::next_nonce`] aren't built on a counter that's drifted
+    /// from on-chain state.
+    pub async fn resync<P: Provider>(&self, provider: &P, address: Address) -> Result<()> {
+        let count = provider
+            .get_transaction_count(address)
+            .await
+            .map_err(|e| TurnkeyError::Configuration(e.to_string()))?;
+
+        self.next.store(count, Ordering::SeqCst);
+        Ok(())
+    }
+}
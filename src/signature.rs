@@ -0,0 +1,42 @@
+use crate::error::TurnkeyError;
+use alloy_primitives::{ChainId, Signature, U256};
+
+/// Assembles a low-S-normalized [`Signature`] from a raw r/s/v triple,
+/// recovering the boolean y-parity Alloy signatures (and new transaction
+/// types) expect regardless of which `v` encoding Turnkey hands back: a raw
+/// recovery id (`0`/`1`), a legacy `v` (`27`/`28`), or an EIP-155 encoded `v`
+/// (`chain_id * 2 + 35`/`+ 36`).
+pub fn normalize_signature(
+    r: U256,
+    s: U256,
+    v: u64,
+    chain_id: Option<ChainId>,
+) -> Result<Signature, TurnkeyError> {
+    let parity = recover_y_parity(v, chain_id)?;
+    let signature = Signature::new(r, s, parity);
+    Ok(signature.normalize_s().unwrap_or(signature))
+}
+
+/// Recovers the boolean y-parity from a raw `v` value.
+fn recover_y_parity(v: u64, chain_id: Option<ChainId>) -> Result<bool, TurnkeyError> {
+    match v {
+        0 | 27 => Ok(false),
+        1 | 28 => Ok(true),
+        v => {
+            if let Some(chain_id) = chain_id {
+                let eip155_base = chain_id * 2 + 35;
+                if v == eip155_base {
+                    return Ok(false);
+                }
+                if v == eip155_base + 1 {
+                    return Ok(true);
+                }
+            }
+
+            Err(TurnkeyError::SignatureParse(match chain_id {
+                Some(chain_id) => format!("unrecognized v value {v} for chain {chain_id}"),
+                None => format!("unrecognized v value {v}"),
+            }))
+        }
+    }
+}
@@ -1,15 +1,45 @@
+use crate::client::{RawSignature, SigningScheme};
 use crate::error::{Result, TurnkeyError};
+use crate::nonce::NonceManager;
+use alloy_consensus::SignableTransaction;
+use alloy_dyn_abi::TypedData;
+use alloy_eips::eip2718::Decodable2718;
+use alloy_network::TxSigner;
 use alloy_primitives::{Address, ChainId, Signature, B256, U256};
+use alloy_provider::Provider;
 use alloy_signer::Signer;
-use turnkey_client::generated::immutable::activity::v1::SignRawPayloadIntentV2;
-use turnkey_client::generated::immutable::common::v1::{HashFunction, PayloadEncoding};
-use turnkey_client::{TurnkeyClient, TurnkeyP256ApiKey};
+use alloy_sol_types::{Eip712Domain, SolStruct};
+use std::sync::Arc;
+use turnkey_client::TurnkeyP256ApiKey;
+
+/// Selects which account of a Turnkey wallet [`TurnkeySigner::from_wallet`]
+/// should resolve to: either a standard BIP-44 Ethereum address index, or an
+/// explicit derivation path for non-standard layouts.
+pub enum WalletAccountSelector {
+    Index(u32),
+    Path(String),
+}
+
+impl WalletAccountSelector {
+    fn derivation_path(&self) -> String {
+        match self {
+            Self::Index(index) => format!("m/44'/60'/0'/0/{index}"),
+            Self::Path(path) => path.clone(),
+        }
+    }
+}
+
+impl From<u32> for WalletAccountSelector {
+    fn from(index: u32) -> Self {
+        Self::Index(index)
+    }
+}
 
 pub struct TurnkeySigner {
-    client: TurnkeyClient<TurnkeyP256ApiKey>,
-    organization_id: String,
+    client: crate::client::TurnkeyClient,
     address: Address,
     chain_id: Option<ChainId>,
+    nonce_manager: Option<Arc<NonceManager>>,
 }
 
 impl TurnkeySigner {
@@ -19,17 +49,21 @@ impl TurnkeySigner {
         address: Address,
         api_key: TurnkeyP256ApiKey,
     ) -> Result<Self> {
-        let client = TurnkeyClient::builder()
-            .api_key(api_key)
-            .build()
-            .map_err(|e| TurnkeyError::Configuration(e.to_string()))?;
+        let client = crate::client::TurnkeyClient::new(organization_id, api_key)?;
+        Ok(Self::from_client(client, address))
+    }
 
-        Ok(Self {
+    /// Assembles a signer around an already-configured
+    /// [`crate::client::TurnkeyClient`], so callers that have one on hand
+    /// (e.g. [`TurnkeySigner::from_wallet`], which needs one to resolve the
+    /// address first) don't build a second one from scratch.
+    fn from_client(client: crate::client::TurnkeyClient, address: Address) -> Self {
+        Self {
             client,
-            organization_id,
             address,
             chain_id: None,
-        })
+            nonce_manager: None,
+        }
     }
 
     /// Set the chain ID for this signer
@@ -37,6 +71,98 @@ impl TurnkeySigner {
         self.chain_id = chain_id;
         self
     }
+
+    /// Override the retry/backoff policy used for activity submission and
+    /// for polling a pending activity (e.g. one awaiting multi-approver
+    /// consensus) to completion.
+    pub fn with_retry_policy(mut self, retry_policy: crate::client::RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Attach a [`NonceManager`] so callers building transactions for this
+    /// signer can pull a locally-tracked, monotonically increasing nonce
+    /// instead of racing each other against `eth_getTransactionCount`.
+    ///
+    /// Turnkey's `SIGN_TRANSACTION_V2` activity completes asynchronously, so
+    /// without this two transactions built back-to-back can observe the same
+    /// on-chain nonce and collide once both reach Turnkey.
+    pub fn with_nonce_manager(mut self, nonce_manager: Arc<NonceManager>) -> Self {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
+
+    /// Hands out the next locally-tracked nonce for this signer's address.
+    ///
+    /// Callers should use this to fill in the nonce on the transaction
+    /// they're building *before* handing it to [`TxSigner::sign_transaction`],
+    /// since by the time Turnkey is asked to sign, the unsigned transaction
+    /// is already final. Requires a nonce manager configured via
+    /// [`TurnkeySigner::with_nonce_manager`].
+    pub fn next_nonce(&self) -> Result<u64> {
+        self.nonce_manager
+            .as_ref()
+            .map(|manager| manager.next_nonce())
+            .ok_or_else(|| {
+                TurnkeyError::Configuration(
+                    "no nonce manager configured; call TurnkeySigner::with_nonce_manager"
+                        .to_string(),
+                )
+            })
+    }
+
+    /// Fills in `tx`'s nonce from the configured [`NonceManager`], if one is
+    /// set via [`TurnkeySigner::with_nonce_manager`]. A no-op otherwise, so
+    /// callers that haven't opted into a nonce manager keep resolving the
+    /// nonce themselves (e.g. via a fresh `eth_getTransactionCount`).
+    ///
+    /// Must run before `tx` is turned into a [`SignableTransaction`] and
+    /// handed to [`TxSigner::sign_transaction`] — by then the nonce can no
+    /// longer be set.
+    pub fn fill_nonce<B: alloy_network::TransactionBuilder<alloy_network::Ethereum>>(
+        &self,
+        tx: &mut B,
+    ) {
+        if let Some(manager) = &self.nonce_manager {
+            tx.set_nonce(manager.next_nonce());
+        }
+    }
+
+    /// Re-syncs the nonce manager from on-chain state, discarding the local
+    /// counter.
+    ///
+    /// Call this after a detected gap (a transaction that failed to land, a
+    /// nonce-too-low response, etc.) so the next [`TurnkeySigner::next_nonce`]
+    /// isn't built on a counter that's drifted from what the chain actually
+    /// has. A no-op if no nonce manager is configured.
+    pub async fn resync_nonce<P: Provider>(&self, provider: &P) -> Result<()> {
+        match &self.nonce_manager {
+            Some(manager) => manager.resync(provider, self.address).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Resolves a signer for one account of an existing wallet by deriving
+    /// its address, instead of requiring the caller to already know the
+    /// address up front.
+    pub async fn from_wallet(
+        organization_id: String,
+        wallet_id: &str,
+        selector: impl Into<WalletAccountSelector>,
+        api_key: TurnkeyP256ApiKey,
+    ) -> Result<Self> {
+        let client = crate::client::TurnkeyClient::new(organization_id, api_key)?;
+
+        let account = client
+            .derive_account(wallet_id, &selector.into().derivation_path())
+            .await?;
+        let address = account
+            .address
+            .parse::<Address>()
+            .map_err(|e| TurnkeyError::InvalidAddress(e.to_string()))?;
+
+        Ok(Self::from_client(client, address))
+    }
 }
 
 #[async_trait::async_trait]
@@ -56,63 +182,111 @@ impl Signer<Signature> for TurnkeySigner {
     async fn sign_hash(&self, hash: &B256) -> std::result::Result<Signature, alloy_signer::Error> {
         let payload = hex::encode(hash.as_slice());
 
-        let intent = SignRawPayloadIntentV2 {
-            sign_with: self.address.to_string(),
-            payload,
-            encoding: PayloadEncoding::Hexadecimal,
-            hash_function: HashFunction::NoOp,
-        };
-
         let response = self
             .client
-            .sign_raw_payload(
-                self.organization_id.clone(),
-                self.client.current_timestamp(),
-                intent,
+            .sign_raw_payload_with_scheme(
+                &self.address.to_string(),
+                &payload,
+                SigningScheme::EcdsaSecp256k1,
             )
             .await
-            .map_err(|e| alloy_signer::Error::other(format!("Turnkey API error: {e}")))?;
-
-        // Parse signature components
-        let r_bytes = hex::decode(&response.r)
-            .map_err(|e| alloy_signer::Error::other(format!("Invalid r: {e}")))?;
-        let s_bytes = hex::decode(&response.s)
-            .map_err(|e| alloy_signer::Error::other(format!("Invalid s: {e}")))?;
-        let v: u64 = response
-            .v
+            .map_err(alloy_signer::Error::other)?;
+
+        let (r, s, v) = match response {
+            RawSignature::EcdsaSecp256k1 { r, s, v } => (r, s, v),
+            RawSignature::Ed25519 { .. } => {
+                unreachable!("sign_hash always requests SigningScheme::EcdsaSecp256k1")
+            }
+        };
+
+        let r_bytes =
+            hex::decode(&r).map_err(|e| alloy_signer::Error::other(format!("Invalid r: {e}")))?;
+        let s_bytes =
+            hex::decode(&s).map_err(|e| alloy_signer::Error::other(format!("Invalid s: {e}")))?;
+        let v: u64 = v
             .parse()
             .map_err(|e| alloy_signer::Error::other(format!("Invalid v: {e}")))?;
 
         let r = U256::from_be_slice(&r_bytes);
         let s = U256::from_be_slice(&s_bytes);
 
-        let parity = match v {
-            27 => false,
-            28 => true,
-            0 => false,
-            1 => true,
-            _ => {
-                if let Some(chain_id) = self.chain_id {
-                    let expected_base = chain_id * 2 + 35;
-                    match v {
-                        v if v == expected_base => false,
-                        v if v == expected_base + 1 => true,
-                        _ => {
-                            return Err(alloy_signer::Error::other(format!(
-                                "Invalid v value for chain {chain_id}: {v}"
-                            )))
-                        }
-                    }
-                } else {
-                    return Err(alloy_signer::Error::other(format!("Invalid v value: {v}")));
-                }
-            }
-        };
+        crate::signature::normalize_signature(r, s, v, self.chain_id)
+            .map_err(alloy_signer::Error::other)
+    }
 
-        Ok(Signature::new(r, s, parity))
+    async fn sign_typed_data<T: SolStruct + Send + Sync>(
+        &self,
+        payload: &T,
+        domain: &Eip712Domain,
+    ) -> std::result::Result<Signature, alloy_signer::Error> {
+        let hash = payload.eip712_signing_hash(domain);
+        self.sign_hash(&hash).await
+    }
+
+    async fn sign_dynamic_typed_data(
+        &self,
+        payload: &TypedData,
+    ) -> std::result::Result<Signature, alloy_signer::Error> {
+        let hash = payload
+            .eip712_signing_hash()
+            .map_err(alloy_signer::Error::other)?;
+        self.sign_hash(&hash).await
     }
 
     fn with_chain_id(self, chain_id: Option<ChainId>) -> Self {
         Self::with_chain_id(self, chain_id)
     }
 }
+
+#[async_trait::async_trait]
+impl TxSigner<Signature> for TurnkeySigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Signs a typed transaction via Turnkey's `SIGN_TRANSACTION_V2` activity.
+    ///
+    /// Unlike [`Signer::sign_hash`], this hands Turnkey the RLP-encoded
+    /// unsigned transaction directly so Turnkey's own nonce/chain validation
+    /// runs against it, rather than signing a locally computed hash.
+    ///
+    /// The nonce on `tx` must already be set by the time it reaches this
+    /// method — Turnkey signs exactly the unsigned transaction it's handed,
+    /// and [`SignableTransaction`] has no nonce setter of its own to fall
+    /// back on. Call [`TurnkeySigner::fill_nonce`] on the builder while
+    /// constructing `tx`, rather than trusting a fresh
+    /// `eth_getTransactionCount` call per transaction; Turnkey's activity
+    /// latency otherwise makes it easy for two in-flight transactions to
+    /// collide on the same nonce.
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<Signature>,
+    ) -> std::result::Result<Signature, alloy_signer::Error> {
+        let mut encoded = Vec::new();
+        tx.encode_for_signing(&mut encoded);
+        let unsigned_transaction = hex::encode(&encoded);
+
+        let response = self
+            .client
+            .sign_transaction_v2(&self.address.to_string(), &unsigned_transaction)
+            .await
+            .map_err(alloy_signer::Error::other)?;
+
+        let signed_bytes = hex::decode(
+            response
+                .signed_transaction
+                .strip_prefix("0x")
+                .unwrap_or(&response.signed_transaction),
+        )
+        .map_err(|e| alloy_signer::Error::other(format!("Invalid signed transaction hex: {e}")))?;
+
+        let envelope = alloy_consensus::TxEnvelope::decode_2718(&mut signed_bytes.as_slice())
+            .map_err(|e| {
+                alloy_signer::Error::other(format!("Failed to decode signed transaction: {e}"))
+            })?;
+
+        envelope.signature().copied().ok_or_else(|| {
+            alloy_signer::Error::other("Turnkey returned a transaction without a signature")
+        })
+    }
+}
@@ -50,6 +50,30 @@ pub struct SignTransactionResult {
     pub signed_transaction: String,
 }
 
+// Sign Transaction V2 Types. Unlike V1, `signWith` addresses the signer
+// polymorphically (a private key id, wallet account address, or wallet id)
+// instead of requiring a pre-resolved private key id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignTransactionV2Request {
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    #[serde(rename = "organizationId")]
+    pub organization_id: String,
+    pub parameters: SignTransactionV2Parameters,
+    #[serde(rename = "timestampMs")]
+    pub timestamp_ms: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignTransactionV2Parameters {
+    #[serde(rename = "signWith")]
+    pub sign_with: String,
+    #[serde(rename = "unsignedTransaction")]
+    pub unsigned_transaction: String,
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+}
+
 // Sign Raw Payload Types (for message signing)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignRawPayloadRequest {
@@ -79,6 +103,140 @@ pub struct SignRawPayloadResult {
     pub v: String,
 }
 
+// Wallet Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Wallet {
+    #[serde(rename = "walletId")]
+    pub wallet_id: String,
+    #[serde(rename = "walletName")]
+    pub wallet_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWalletsResult {
+    pub wallets: Vec<Wallet>,
+}
+
+// Wallet Account Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletAccount {
+    #[serde(rename = "walletAccountId")]
+    pub wallet_account_id: String,
+    pub address: String,
+    #[serde(rename = "publicKey")]
+    pub public_key: String,
+    pub curve: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWalletAccountsResult {
+    pub accounts: Vec<WalletAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletAccountsRequest {
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    #[serde(rename = "organizationId")]
+    pub organization_id: String,
+    pub parameters: CreateWalletAccountsParameters,
+    #[serde(rename = "timestampMs")]
+    pub timestamp_ms: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletAccountsParameters {
+    #[serde(rename = "walletId")]
+    pub wallet_id: String,
+    pub accounts: Vec<WalletAccountParams>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletAccountParams {
+    pub curve: String,
+    #[serde(rename = "pathFormat")]
+    pub path_format: String,
+    pub path: String,
+    #[serde(rename = "addressFormat")]
+    pub address_format: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletAccountsResult {
+    pub addresses: Vec<String>,
+}
+
+// Export / Import Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportWalletRequest {
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    #[serde(rename = "organizationId")]
+    pub organization_id: String,
+    pub parameters: ExportWalletParameters,
+    #[serde(rename = "timestampMs")]
+    pub timestamp_ms: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportWalletParameters {
+    #[serde(rename = "walletId")]
+    pub wallet_id: String,
+    #[serde(rename = "targetPublicKey")]
+    pub target_public_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportPrivateKeyRequest {
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    #[serde(rename = "organizationId")]
+    pub organization_id: String,
+    pub parameters: ExportPrivateKeyParameters,
+    #[serde(rename = "timestampMs")]
+    pub timestamp_ms: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportPrivateKeyParameters {
+    #[serde(rename = "privateKeyId")]
+    pub private_key_id: String,
+    #[serde(rename = "targetPublicKey")]
+    pub target_public_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportResult {
+    #[serde(rename = "exportBundle")]
+    pub export_bundle: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportWalletRequest {
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    #[serde(rename = "organizationId")]
+    pub organization_id: String,
+    pub parameters: ImportWalletParameters,
+    #[serde(rename = "timestampMs")]
+    pub timestamp_ms: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportWalletParameters {
+    #[serde(rename = "walletName")]
+    pub wallet_name: String,
+    #[serde(rename = "encryptedBundle")]
+    pub encrypted_bundle: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportWalletResult {
+    #[serde(rename = "walletId")]
+    pub wallet_id: String,
+}
+
 // Generic API Response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TurnkeyApiResponse<T> {
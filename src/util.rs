@@ -0,0 +1,12 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Adds up to 20% random jitter to a delay so concurrent retries don't
+/// synchronize on the same schedule.
+pub(crate) fn jitter(delay: Duration) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos() as u64;
+    let jitter_fraction = (nanos % 1000) as f64 / 1000.0 * 0.2;
+    delay.mul_f64(1.0 + jitter_fraction)
+}
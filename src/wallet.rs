@@ -0,0 +1,89 @@
+use crate::error::{Result, TurnkeyError};
+use crate::signer::TurnkeySigner;
+use alloy_primitives::{Address, Signature, B256};
+use alloy_signer::Signer;
+use std::collections::HashSet;
+use turnkey_client::TurnkeyP256ApiKey;
+
+/// A pool of Turnkey-custodied Ethereum addresses backed by a single API key.
+///
+/// `TurnkeySigner` is pinned to one address; `TurnkeyWallet` discovers every
+/// address held by the organization's wallets and hands out a `TurnkeySigner`
+/// for whichever address a caller needs, so services that manage many
+/// Turnkey-custodied keys don't have to construct a signer per address by
+/// hand.
+pub struct TurnkeyWallet {
+    api_key: TurnkeyP256ApiKey,
+    organization_id: String,
+    addresses: HashSet<Address>,
+}
+
+impl TurnkeyWallet {
+    /// Discover every Ethereum address held across the organization's wallets.
+    ///
+    /// Goes through [`crate::client::TurnkeyClient`] rather than the vendored
+    /// SDK, so wallet discovery shares one HTTP backend with every other
+    /// activity this crate submits instead of maintaining a second one. The
+    /// list queries it sends are not retried.
+    pub async fn discover(organization_id: String, api_key: TurnkeyP256ApiKey) -> Result<Self>
+    where
+        TurnkeyP256ApiKey: Clone,
+    {
+        let client = crate::client::TurnkeyClient::new(organization_id.clone(), api_key.clone())?;
+
+        let mut addresses = HashSet::new();
+        for wallet in client.list_wallets().await? {
+            for account in client.list_wallet_accounts(&wallet.wallet_id).await? {
+                if let Ok(address) = account.address.parse::<Address>() {
+                    addresses.insert(address);
+                }
+            }
+        }
+
+        Ok(Self {
+            api_key,
+            organization_id,
+            addresses,
+        })
+    }
+
+    /// Addresses discovered for this organization.
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> {
+        self.addresses.iter()
+    }
+
+    /// Whether this wallet manages the given address.
+    pub fn contains(&self, address: &Address) -> bool {
+        self.addresses.contains(address)
+    }
+
+    /// Build a `TurnkeySigner` for one of this wallet's addresses.
+    pub fn signer_for(&self, address: &Address) -> Result<TurnkeySigner>
+    where
+        TurnkeyP256ApiKey: Clone,
+    {
+        if !self.addresses.contains(address) {
+            return Err(TurnkeyError::InvalidAddress(format!(
+                "{address} is not managed by this wallet"
+            )));
+        }
+
+        TurnkeySigner::new(self.organization_id.clone(), *address, self.api_key.clone())
+    }
+
+    /// Sign a hash with one of this wallet's addresses without constructing
+    /// a standalone `TurnkeySigner` first.
+    pub async fn sign_hash(
+        &self,
+        address: &Address,
+        hash: &B256,
+    ) -> std::result::Result<Signature, alloy_signer::Error>
+    where
+        TurnkeyP256ApiKey: Clone,
+    {
+        let signer = self
+            .signer_for(address)
+            .map_err(|e| alloy_signer::Error::other(e.to_string()))?;
+        signer.sign_hash(hash).await
+    }
+}
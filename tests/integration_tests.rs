@@ -70,3 +70,66 @@ fn test_address_handling() {
     let addr: Address = address();
     assert_eq!(addr.to_string().len(), 42); // 0x + 40 hex chars
 }
+
+#[test]
+fn test_normalize_signature_raw_recovery_id() {
+    use alloy_primitives::U256;
+    use alloy_signer_turnkey::signature::normalize_signature;
+
+    let r = U256::from(1u64);
+    let s = U256::from(2u64);
+
+    let sig0 = normalize_signature(r, s, 0, None).unwrap();
+    assert!(!sig0.v());
+
+    let sig1 = normalize_signature(r, s, 1, None).unwrap();
+    assert!(sig1.v());
+}
+
+#[test]
+fn test_normalize_signature_legacy_v() {
+    use alloy_primitives::U256;
+    use alloy_signer_turnkey::signature::normalize_signature;
+
+    let r = U256::from(1u64);
+    let s = U256::from(2u64);
+
+    assert!(!normalize_signature(r, s, 27, None).unwrap().v());
+    assert!(normalize_signature(r, s, 28, None).unwrap().v());
+}
+
+#[test]
+fn test_normalize_signature_eip155_v() {
+    use alloy_primitives::U256;
+    use alloy_signer_turnkey::signature::normalize_signature;
+
+    let r = U256::from(1u64);
+    let s = U256::from(2u64);
+    let chain_id: ChainId = 1;
+
+    assert!(!normalize_signature(r, s, chain_id * 2 + 35, Some(chain_id))
+        .unwrap()
+        .v());
+    assert!(normalize_signature(r, s, chain_id * 2 + 36, Some(chain_id))
+        .unwrap()
+        .v());
+}
+
+#[test]
+fn test_normalize_signature_rejects_unrecognized_v_without_chain_id() {
+    use alloy_primitives::U256;
+    use alloy_signer_turnkey::signature::normalize_signature;
+
+    let r = U256::from(1u64);
+    let s = U256::from(2u64);
+
+    assert!(normalize_signature(r, s, 99, None).is_err());
+}
+
+#[tokio::test]
+async fn test_next_nonce_requires_configured_nonce_manager() {
+    let api_key = TurnkeyP256ApiKey::generate();
+    let signer = TurnkeySigner::new(ORGANIZATION_ID.to_string(), address(), api_key).unwrap();
+
+    assert!(signer.next_nonce().is_err());
+}